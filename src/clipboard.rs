@@ -1,45 +1,511 @@
 use crate::error::ClipboardError;
-use arboard::Clipboard;
+use arboard::{Clipboard, ImageData};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Which clipboard selection to target on Linux, where the CLIPBOARD and
+/// PRIMARY (middle-click) selections are independent.
+///
+/// Honored by the `Arboard` backend (Linux only) and the `Osc52` backend, and
+/// by `Command` backends whose detected or configured tool has a primary
+/// variant (`wl-copy`/`xclip`/`xsel`). Requesting `Primary` against a backend
+/// with no primary variant (e.g. `pbcopy`, a custom command, or `Arboard`
+/// on a non-Linux platform) is silently treated as `Clipboard` instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Selection {
+    /// The regular clipboard (copy/paste)
+    #[default]
+    Clipboard,
+    /// The X11 primary selection, populated by mouse selection
+    Primary,
+}
+
+/// The default cap on an OSC 52 payload, in base64-encoded bytes.
+///
+/// Many terminals (and tmux in particular) silently drop OSC 52 sequences
+/// above a certain size, so we reject oversized payloads up front rather
+/// than send a sequence the terminal will ignore.
+pub const DEFAULT_OSC52_MAX_LEN: usize = 100_000;
+
+/// Which X11-style selection an OSC 52 write targets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Osc52Target {
+    /// The regular clipboard (`c`)
+    Clipboard,
+    /// The X11 primary selection, populated by mouse selection (`p`)
+    Primary,
+}
+
+impl From<Selection> for Osc52Target {
+    fn from(selection: Selection) -> Self {
+        match selection {
+            Selection::Clipboard => Self::Clipboard,
+            Selection::Primary => Self::Primary,
+        }
+    }
+}
+
+/// Selects which external commands (if any) `ClipboardManager` shells out to.
+///
+/// `Arboard` talks to the native clipboard directly, and is what `detect`
+/// falls back to when no other backend applies. `Command` instead spawns a `copy` command and pipes
+/// text into its stdin, and a `paste` command and reads text from its
+/// stdout - this is what lets klip work over SSH, under WSL, or against a
+/// Wayland/X11 clipboard tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardBackend {
+    /// Use the native clipboard via `arboard`
+    Arboard,
+    /// Shell out to external commands to copy and paste.
+    ///
+    /// `primary_copy`/`primary_paste` are only set when the underlying tool
+    /// has a distinct primary-selection mode (`wl-copy`, `xclip`, `xsel`);
+    /// they're `None` for tools with no such concept (`clip.exe`, `pbcopy`,
+    /// `win32yank`, custom commands).
+    Command {
+        copy: Vec<String>,
+        paste: Vec<String>,
+        primary_copy: Option<Vec<String>>,
+        primary_paste: Option<Vec<String>>,
+    },
+    /// Write an OSC 52 terminal escape sequence to set the user's local
+    /// clipboard, for remote hosts (SSH) with no local clipboard of their own
+    Osc52 { max_len: usize },
+}
+
+impl ClipboardBackend {
+    /// Detects the most appropriate backend for the current environment.
+    ///
+    /// Checks, in order: WSL (via `clip.exe`/`powershell.exe`), SSH with no
+    /// local display server (via an OSC 52 escape sequence), Wayland (via
+    /// `wl-copy`/`wl-paste`), X11 (via `xclip` or `xsel`), then macOS (via
+    /// `pbcopy`/`pbpaste`). Falls back to `arboard` if none of those apply.
+    ///
+    /// `osc52_max_len` is used as the payload limit if auto-detection picks
+    /// the OSC 52 backend (see `Osc52`).
+    pub fn detect(osc52_max_len: usize) -> Self {
+        if env::var_os("WSL_DISTRO_NAME").is_some() || env::var_os("WSLENV").is_some() {
+            return Self::Command {
+                copy: vec!["clip.exe".to_string()],
+                paste: vec![
+                    "powershell.exe".to_string(),
+                    "-NoProfile".to_string(),
+                    "-Command".to_string(),
+                    "Get-Clipboard".to_string(),
+                ],
+                primary_copy: None,
+                primary_paste: None,
+            };
+        }
+
+        let has_display =
+            env::var_os("WAYLAND_DISPLAY").is_some() || env::var_os("DISPLAY").is_some();
+        let is_ssh = env::var_os("SSH_CONNECTION").is_some() || env::var_os("SSH_TTY").is_some();
+        if is_ssh && !has_display {
+            return Self::Osc52 {
+                max_len: osc52_max_len,
+            };
+        }
+
+        if env::var_os("WAYLAND_DISPLAY").is_some() && command_exists("wl-copy") {
+            return Self::Command {
+                copy: vec!["wl-copy".to_string()],
+                paste: vec!["wl-paste".to_string(), "--no-newline".to_string()],
+                primary_copy: Some(vec!["wl-copy".to_string(), "--primary".to_string()]),
+                primary_paste: Some(vec![
+                    "wl-paste".to_string(),
+                    "--primary".to_string(),
+                    "--no-newline".to_string(),
+                ]),
+            };
+        }
+
+        if env::var_os("DISPLAY").is_some() {
+            if command_exists("xclip") {
+                return Self::Command {
+                    copy: vec![
+                        "xclip".to_string(),
+                        "-selection".to_string(),
+                        "clipboard".to_string(),
+                    ],
+                    paste: vec![
+                        "xclip".to_string(),
+                        "-selection".to_string(),
+                        "clipboard".to_string(),
+                        "-o".to_string(),
+                    ],
+                    primary_copy: Some(vec![
+                        "xclip".to_string(),
+                        "-selection".to_string(),
+                        "primary".to_string(),
+                    ]),
+                    primary_paste: Some(vec![
+                        "xclip".to_string(),
+                        "-selection".to_string(),
+                        "primary".to_string(),
+                        "-o".to_string(),
+                    ]),
+                };
+            }
+
+            if command_exists("xsel") {
+                return Self::Command {
+                    copy: vec![
+                        "xsel".to_string(),
+                        "--clipboard".to_string(),
+                        "--input".to_string(),
+                    ],
+                    paste: vec![
+                        "xsel".to_string(),
+                        "--clipboard".to_string(),
+                        "--output".to_string(),
+                    ],
+                    primary_copy: Some(vec![
+                        "xsel".to_string(),
+                        "--primary".to_string(),
+                        "--input".to_string(),
+                    ]),
+                    primary_paste: Some(vec![
+                        "xsel".to_string(),
+                        "--primary".to_string(),
+                        "--output".to_string(),
+                    ]),
+                };
+            }
+        }
+
+        if cfg!(target_os = "macos") {
+            return Self::Command {
+                copy: vec!["pbcopy".to_string()],
+                paste: vec!["pbpaste".to_string()],
+                primary_copy: None,
+                primary_paste: None,
+            };
+        }
+
+        Self::Arboard
+    }
+}
+
+/// Checks whether `bin` is on `PATH`.
+fn command_exists(bin: &str) -> bool {
+    Command::new("which")
+        .arg(bin)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// The resolved, ready-to-use state backing a `ClipboardManager`.
+enum Resolved {
+    Arboard(Clipboard),
+    Command {
+        copy: Vec<String>,
+        paste: Vec<String>,
+        primary_copy: Option<Vec<String>>,
+        primary_paste: Option<Vec<String>>,
+    },
+    Osc52 {
+        max_len: usize,
+    },
+}
 
 /// Manages clipboard operations across platforms
 pub struct ClipboardManager {
-    clipboard: Clipboard,
+    resolved: Resolved,
 }
 
 impl ClipboardManager {
-    /// Creates a new clipboard manager
-    pub fn new() -> Result<Self, ClipboardError> {
-        let clipboard = Clipboard::new()
-            .map_err(|e| ClipboardError::InitializationFailed(e.to_string()))?;
+    /// Creates a new clipboard manager using the given backend
+    pub fn new(backend: ClipboardBackend) -> Result<Self, ClipboardError> {
+        let resolved = match backend {
+            ClipboardBackend::Arboard => {
+                let clipboard = Clipboard::new()
+                    .map_err(|e| ClipboardError::InitializationFailed(e.to_string()))?;
+                Resolved::Arboard(clipboard)
+            }
+            ClipboardBackend::Command {
+                copy,
+                paste,
+                primary_copy,
+                primary_paste,
+            } => {
+                if copy.is_empty() || paste.is_empty() {
+                    return Err(ClipboardError::InitializationFailed(
+                        "command clipboard backend requires non-empty copy and paste commands"
+                            .to_string(),
+                    ));
+                }
+                Resolved::Command {
+                    copy,
+                    paste,
+                    primary_copy,
+                    primary_paste,
+                }
+            }
+            ClipboardBackend::Osc52 { max_len } => Resolved::Osc52 { max_len },
+        };
 
-        Ok(Self { clipboard })
+        Ok(Self { resolved })
     }
 
-    /// Copies text to the system clipboard
-    pub fn copy(&mut self, text: &str) -> Result<(), ClipboardError> {
-        self.clipboard
-            .set_text(text)
-            .map_err(|e| ClipboardError::CopyFailed(e.to_string()))
+    /// Copies text to the system clipboard, or (where supported) the primary selection
+    pub fn copy(&mut self, text: &str, selection: Selection) -> Result<(), ClipboardError> {
+        match &mut self.resolved {
+            Resolved::Arboard(clipboard) => set_text(clipboard, text, selection),
+            Resolved::Command {
+                copy, primary_copy, ..
+            } => match selection {
+                Selection::Clipboard => run_copy_command(copy, text),
+                // No primary-selection variant configured for this command
+                // (e.g. pbcopy, win32yank, a custom command): fall back to
+                // the regular clipboard rather than erroring.
+                Selection::Primary => {
+                    run_copy_command(primary_copy.as_ref().unwrap_or(&*copy), text)
+                }
+            },
+            Resolved::Osc52 { max_len } => write_osc52(text, selection.into(), *max_len),
+        }
+    }
+
+    /// Gets current clipboard content, or (where supported) the primary selection
+    pub fn get(&mut self, selection: Selection) -> Result<String, ClipboardError> {
+        match &mut self.resolved {
+            Resolved::Arboard(clipboard) => get_text(clipboard, selection),
+            Resolved::Command {
+                paste,
+                primary_paste,
+                ..
+            } => match selection {
+                Selection::Clipboard => run_paste_command(paste),
+                // No primary-selection variant configured for this command:
+                // fall back to the regular clipboard rather than erroring.
+                Selection::Primary => run_paste_command(primary_paste.as_ref().unwrap_or(&*paste)),
+            },
+            Resolved::Osc52 { .. } => Err(ClipboardError::ReadFailed(
+                "reading the clipboard is not supported with the OSC 52 backend".to_string(),
+            )),
+        }
     }
 
-    /// Gets current clipboard content
-    pub fn get(&mut self) -> Result<String, ClipboardError> {
-        self.clipboard
-            .get_text()
-            .map_err(|e| ClipboardError::ReadFailed(e.to_string()))
+    /// Copies RGBA image data to the system clipboard.
+    ///
+    /// Only supported when using the `Arboard` backend - command-based and
+    /// OSC 52 backends have no portable way to set clipboard image data.
+    pub fn copy_image(&mut self, image: ImageData<'_>) -> Result<(), ClipboardError> {
+        match &mut self.resolved {
+            Resolved::Arboard(clipboard) => clipboard
+                .set_image(image)
+                .map_err(|e| ClipboardError::CopyFailed(e.to_string())),
+            Resolved::Command { .. } => Err(ClipboardError::CopyFailed(
+                "copying images is not supported with a command-based clipboard backend"
+                    .to_string(),
+            )),
+            Resolved::Osc52 { .. } => Err(ClipboardError::CopyFailed(
+                "copying images is not supported with the OSC 52 backend".to_string(),
+            )),
+        }
+    }
+
+    /// Gets current clipboard image content as RGBA pixel data.
+    ///
+    /// Only supported when using the `Arboard` backend - command-based and
+    /// OSC 52 backends have no portable way to read clipboard image data.
+    pub fn get_image(&mut self) -> Result<ImageData<'static>, ClipboardError> {
+        match &mut self.resolved {
+            Resolved::Arboard(clipboard) => clipboard
+                .get_image()
+                .map_err(|e| ClipboardError::ReadFailed(e.to_string())),
+            Resolved::Command { .. } => Err(ClipboardError::ReadFailed(
+                "reading images is not supported with a command-based clipboard backend"
+                    .to_string(),
+            )),
+            Resolved::Osc52 { .. } => Err(ClipboardError::ReadFailed(
+                "reading images is not supported with the OSC 52 backend".to_string(),
+            )),
+        }
+    }
+}
+
+/// Writes an OSC 52 escape sequence setting `text` onto the terminal's
+/// clipboard (or primary selection), via `/dev/tty` if available, falling
+/// back to stderr so the sequence never corrupts klip's own stdout.
+fn write_osc52(text: &str, target: Osc52Target, max_len: usize) -> Result<(), ClipboardError> {
+    let sequence = build_osc52_sequence(text, target, max_len)?;
+
+    let write_result = match OpenOptions::new().write(true).open("/dev/tty") {
+        Ok(mut tty) => tty.write_all(sequence.as_bytes()),
+        Err(_) => std::io::stderr().write_all(sequence.as_bytes()),
+    };
+
+    write_result.map_err(|e| {
+        ClipboardError::CopyFailed(format!("failed to write OSC 52 sequence to terminal: {e}"))
+    })
+}
+
+/// Builds the `ESC ] 52 ; <selector> ; <base64> BEL` sequence for `text`,
+/// rejecting payloads over `max_len` base64-encoded bytes
+fn build_osc52_sequence(
+    text: &str,
+    target: Osc52Target,
+    max_len: usize,
+) -> Result<String, ClipboardError> {
+    let encoded = BASE64.encode(text.as_bytes());
+
+    if encoded.len() > max_len {
+        return Err(ClipboardError::CopyFailed(format!(
+            "OSC 52 payload of {} bytes exceeds the configured limit of {max_len} bytes",
+            encoded.len()
+        )));
+    }
+
+    let selector = match target {
+        Osc52Target::Clipboard => 'c',
+        Osc52Target::Primary => 'p',
+    };
+
+    Ok(format!("\x1b]52;{selector};{encoded}\x07"))
+}
+
+/// Sets the clipboard/primary selection's text content via `arboard`
+#[cfg(target_os = "linux")]
+fn set_text(
+    clipboard: &mut Clipboard,
+    text: &str,
+    selection: Selection,
+) -> Result<(), ClipboardError> {
+    use arboard::SetExtLinux;
+
+    clipboard
+        .set()
+        .clipboard(selection.into())
+        .text(text)
+        .map_err(|e| ClipboardError::CopyFailed(e.to_string()))
+}
+
+/// Sets the clipboard's text content via `arboard`
+#[cfg(not(target_os = "linux"))]
+fn set_text(
+    clipboard: &mut Clipboard,
+    text: &str,
+    _selection: Selection,
+) -> Result<(), ClipboardError> {
+    clipboard
+        .set_text(text)
+        .map_err(|e| ClipboardError::CopyFailed(e.to_string()))
+}
+
+/// Reads the clipboard/primary selection's text content via `arboard`
+#[cfg(target_os = "linux")]
+fn get_text(clipboard: &mut Clipboard, selection: Selection) -> Result<String, ClipboardError> {
+    use arboard::GetExtLinux;
+
+    clipboard
+        .get()
+        .clipboard(selection.into())
+        .text()
+        .map_err(|e| match e {
+            arboard::Error::ContentNotAvailable => ClipboardError::NonTextData,
+            e => ClipboardError::ReadFailed(e.to_string()),
+        })
+}
+
+/// Reads the clipboard's text content via `arboard`
+#[cfg(not(target_os = "linux"))]
+fn get_text(clipboard: &mut Clipboard, _selection: Selection) -> Result<String, ClipboardError> {
+    clipboard.get_text().map_err(|e| match e {
+        arboard::Error::ContentNotAvailable => ClipboardError::NonTextData,
+        e => ClipboardError::ReadFailed(e.to_string()),
+    })
+}
+
+#[cfg(target_os = "linux")]
+impl From<Selection> for arboard::LinuxClipboardKind {
+    fn from(selection: Selection) -> Self {
+        match selection {
+            Selection::Clipboard => arboard::LinuxClipboardKind::Clipboard,
+            Selection::Primary => arboard::LinuxClipboardKind::Primary,
+        }
     }
 }
 
+/// Spawns `command`, writing `text` to its stdin
+fn run_copy_command(command: &[String], text: &str) -> Result<(), ClipboardError> {
+    let (bin, args) = command
+        .split_first()
+        .ok_or_else(|| ClipboardError::CopyFailed("empty copy command".to_string()))?;
+
+    // klip's own stdout carries the MCP JSON-RPC stream, so the child must
+    // never be allowed to write to it (or to our inherited stderr)
+    let mut child = Command::new(bin)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| ClipboardError::CopyFailed(format!("failed to spawn {bin}: {e}")))?;
+
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| ClipboardError::CopyFailed(format!("failed to open stdin for {bin}")))?
+        .write_all(text.as_bytes())
+        .map_err(|e| ClipboardError::CopyFailed(e.to_string()))?;
+
+    let status = child
+        .wait()
+        .map_err(|e| ClipboardError::CopyFailed(e.to_string()))?;
+
+    if !status.success() {
+        return Err(ClipboardError::CopyFailed(format!(
+            "{bin} exited with {status}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Spawns `command` and captures its stdout as UTF-8 text
+fn run_paste_command(command: &[String]) -> Result<String, ClipboardError> {
+    let (bin, args) = command
+        .split_first()
+        .ok_or_else(|| ClipboardError::ReadFailed("empty paste command".to_string()))?;
+
+    let output = Command::new(bin)
+        .args(args)
+        .output()
+        .map_err(|e| ClipboardError::ReadFailed(format!("failed to spawn {bin}: {e}")))?;
+
+    if !output.status.success() {
+        return Err(ClipboardError::ReadFailed(format!(
+            "{bin} exited with {}",
+            output.status
+        )));
+    }
+
+    String::from_utf8(output.stdout).map_err(|e| ClipboardError::ReadFailed(e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::borrow::Cow;
 
     // Note: These tests require a clipboard (X11/Wayland on Linux, native on Windows/macOS)
     // They will be skipped in headless CI environments
 
     #[test]
     fn test_clipboard_initialization() {
-        let result = ClipboardManager::new();
+        let result = ClipboardManager::new(ClipboardBackend::Arboard);
         // If clipboard is unavailable (headless CI), that's acceptable
         if result.is_err() {
             eprintln!("Clipboard not available in test environment");
@@ -48,57 +514,213 @@ mod tests {
 
     #[test]
     fn test_copy_and_read_text() {
-        let mut manager = match ClipboardManager::new() {
+        let mut manager = match ClipboardManager::new(ClipboardBackend::Arboard) {
             Ok(m) => m,
             Err(e) => {
                 eprintln!("Skipping test - clipboard not available: {}", e);
                 return;
             }
         };
-        
+
         let test_text = "Hello, klip!";
-        
+
         // Copy text
-        let copy_result = manager.copy(test_text);
+        let copy_result = manager.copy(test_text, Selection::Clipboard);
         assert!(copy_result.is_ok());
-        
+
         // Read back
-        let read_result = manager.get();
+        let read_result = manager.get(Selection::Clipboard);
         assert!(read_result.is_ok());
         assert_eq!(read_result.unwrap(), test_text);
     }
 
     #[test]
     fn test_copy_unicode() {
-        let mut manager = match ClipboardManager::new() {
+        let mut manager = match ClipboardManager::new(ClipboardBackend::Arboard) {
             Ok(m) => m,
             Err(e) => {
                 eprintln!("Skipping test - clipboard not available: {}", e);
                 return;
             }
         };
-        
+
         let test_text = "Hello 世界 🌍";
-        
-        let result = manager.copy(test_text);
+
+        let result = manager.copy(test_text, Selection::Clipboard);
         assert!(result.is_ok());
-        
-        let read_result = manager.get();
+
+        let read_result = manager.get(Selection::Clipboard);
         assert!(read_result.is_ok());
         assert_eq!(read_result.unwrap(), test_text);
     }
 
     #[test]
     fn test_copy_empty_string() {
-        let mut manager = match ClipboardManager::new() {
+        let mut manager = match ClipboardManager::new(ClipboardBackend::Arboard) {
             Ok(m) => m,
             Err(e) => {
                 eprintln!("Skipping test - clipboard not available: {}", e);
                 return;
             }
         };
-        
-        let result = manager.copy("");
+
+        let result = manager.copy("", Selection::Clipboard);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_command_backend_requires_commands() {
+        let result = ClipboardManager::new(ClipboardBackend::Command {
+            copy: vec![],
+            paste: vec!["pbpaste".to_string()],
+            primary_copy: None,
+            primary_paste: None,
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_osc52_rejects_oversized_payload() {
+        let mut manager = ClipboardManager::new(ClipboardBackend::Osc52 { max_len: 8 })
+            .expect("OSC 52 backend requires no initialization");
+
+        let result = manager.copy(
+            "this text is far too long for an 8 byte limit",
+            Selection::Clipboard,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_osc52_sequence_selector_follows_selection() {
+        let clipboard_sequence =
+            build_osc52_sequence("hi", Selection::Clipboard.into(), DEFAULT_OSC52_MAX_LEN).unwrap();
+        let primary_sequence =
+            build_osc52_sequence("hi", Selection::Primary.into(), DEFAULT_OSC52_MAX_LEN).unwrap();
+
+        assert!(clipboard_sequence.contains("52;c;"));
+        assert!(primary_sequence.contains("52;p;"));
+    }
+
+    #[test]
+    fn test_osc52_backend_rejects_oversized_primary_payload() {
+        let mut manager = ClipboardManager::new(ClipboardBackend::Osc52 { max_len: 8 })
+            .expect("OSC 52 backend requires no initialization");
+
+        let result = manager.copy(
+            "this text is far too long for an 8 byte limit",
+            Selection::Primary,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_command_backend_falls_back_to_clipboard_when_primary_unconfigured() {
+        let clipboard_file =
+            env::temp_dir().join(format!("klip-test-fallback-{}", std::process::id()));
+        let clipboard_path = clipboard_file.to_string_lossy().into_owned();
+
+        let mut manager = ClipboardManager::new(ClipboardBackend::Command {
+            copy: vec!["tee".to_string(), clipboard_path.clone()],
+            paste: vec!["cat".to_string(), clipboard_path],
+            primary_copy: None,
+            primary_paste: None,
+        })
+        .expect("command backend requires no initialization");
+
+        if manager.copy("text", Selection::Primary).is_err() {
+            eprintln!("Skipping test - `tee`/`cat` not available in test environment");
+            let _ = std::fs::remove_file(&clipboard_file);
+            return;
+        }
+
+        assert_eq!(manager.get(Selection::Primary).unwrap(), "text");
+
+        let _ = std::fs::remove_file(&clipboard_file);
+    }
+
+    #[test]
+    fn test_command_backend_honors_primary_selection_commands() {
+        let clipboard_file =
+            env::temp_dir().join(format!("klip-test-clipboard-{}", std::process::id()));
+        let primary_file =
+            env::temp_dir().join(format!("klip-test-primary-{}", std::process::id()));
+
+        let mut manager = ClipboardManager::new(ClipboardBackend::Command {
+            copy: vec![
+                "tee".to_string(),
+                clipboard_file.to_string_lossy().into_owned(),
+            ],
+            paste: vec![
+                "cat".to_string(),
+                clipboard_file.to_string_lossy().into_owned(),
+            ],
+            primary_copy: Some(vec![
+                "tee".to_string(),
+                primary_file.to_string_lossy().into_owned(),
+            ]),
+            primary_paste: Some(vec![
+                "cat".to_string(),
+                primary_file.to_string_lossy().into_owned(),
+            ]),
+        })
+        .expect("command backend requires no initialization");
+
+        if manager
+            .copy("clipboard text", Selection::Clipboard)
+            .is_err()
+        {
+            eprintln!("Skipping test - `tee`/`cat` not available in test environment");
+            return;
+        }
+        manager
+            .copy("primary text", Selection::Primary)
+            .expect("primary copy command should succeed");
+
+        assert_eq!(manager.get(Selection::Clipboard).unwrap(), "clipboard text");
+        assert_eq!(manager.get(Selection::Primary).unwrap(), "primary text");
+
+        let _ = std::fs::remove_file(&clipboard_file);
+        let _ = std::fs::remove_file(&primary_file);
+    }
+
+    #[test]
+    fn test_selection_defaults_to_clipboard() {
+        assert_eq!(Selection::default(), Selection::Clipboard);
+    }
+
+    #[test]
+    fn test_command_backend_rejects_image_operations() {
+        let mut manager = ClipboardManager::new(ClipboardBackend::Command {
+            copy: vec!["cat".to_string()],
+            paste: vec!["cat".to_string()],
+            primary_copy: None,
+            primary_paste: None,
+        })
+        .expect("command backend requires no initialization");
+
+        let image = ImageData {
+            width: 1,
+            height: 1,
+            bytes: Cow::Owned(vec![0, 0, 0, 255]),
+        };
+        assert!(manager.copy_image(image).is_err());
+        assert!(manager.get_image().is_err());
+    }
+
+    #[test]
+    fn test_osc52_backend_rejects_image_operations() {
+        let mut manager = ClipboardManager::new(ClipboardBackend::Osc52 {
+            max_len: DEFAULT_OSC52_MAX_LEN,
+        })
+        .expect("OSC 52 backend requires no initialization");
+
+        let image = ImageData {
+            width: 1,
+            height: 1,
+            bytes: Cow::Owned(vec![0, 0, 0, 255]),
+        };
+        assert!(manager.copy_image(image).is_err());
+        assert!(manager.get_image().is_err());
+    }
 }
@@ -9,15 +9,12 @@ pub enum ClipboardError {
     #[error("Failed to copy to clipboard: {0}")]
     CopyFailed(String),
 
-    #[allow(dead_code)]
     #[error("Failed to read from clipboard: {0}")]
     ReadFailed(String),
 
-    #[allow(dead_code)]
     #[error("Clipboard contains non-text data")]
     NonTextData,
 
-    #[allow(dead_code)]
     #[error("Clipboard is empty")]
     Empty,
 }
@@ -1,8 +1,13 @@
 mod clipboard;
 mod error;
 
+use arboard::ImageData;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
 use clap::Parser;
-use clipboard::ClipboardManager;
+use clipboard::{ClipboardBackend, ClipboardManager, Selection, DEFAULT_OSC52_MAX_LEN};
+use error::ClipboardError;
+use image::{DynamicImage, ImageFormat};
 use rmcp::handler::server::tool::ToolRouter;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::*;
@@ -14,31 +19,183 @@ use rmcp::ServerHandler;
 use rmcp::ServiceExt;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::env;
+use std::io::Cursor;
 use tracing_subscriber::EnvFilter;
 
 /// Cross-platform MCP server for clipboard operations
 #[derive(Parser, Debug)]
 #[command(name = "klip")]
 #[command(author, version, about, long_about = None)]
-struct Cli {}
+struct Cli {
+    /// Force a specific clipboard backend instead of auto-detecting one
+    #[arg(long, value_enum)]
+    clipboard_provider: Option<ClipboardProviderArg>,
+
+    /// Force writing the clipboard via an OSC 52 terminal escape sequence,
+    /// for remote (SSH) sessions with no local clipboard of their own
+    #[arg(long)]
+    osc52: bool,
+
+    /// Maximum OSC 52 payload size, in base64-encoded bytes, before klip
+    /// rejects it instead of sending a sequence the terminal may silently
+    /// drop. Applies whether OSC 52 was forced via `--osc52` or auto-detected
+    #[arg(long, default_value_t = DEFAULT_OSC52_MAX_LEN)]
+    osc52_max_len: usize,
+}
+
+/// The clipboard backends that can be selected via `--clipboard-provider`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[value(rename_all = "kebab-case")]
+enum ClipboardProviderArg {
+    /// Use the native clipboard via `arboard` (the auto-detection fallback)
+    Arboard,
+    /// Use `wl-copy`/`wl-paste` (Wayland)
+    WlCopy,
+    /// Use `xclip` (X11)
+    Xclip,
+    /// Use `xsel` (X11)
+    Xsel,
+    /// Use `pbcopy`/`pbpaste` (macOS)
+    Pbcopy,
+    /// Use `win32yank` (WSL)
+    Win32yank,
+    /// Use commands from `KLIP_CUSTOM_COPY_COMMAND`/`KLIP_CUSTOM_PASTE_COMMAND`
+    Custom,
+}
+
+impl ClipboardProviderArg {
+    /// Resolves this CLI choice into a concrete `ClipboardBackend`
+    fn into_backend(self) -> Result<ClipboardBackend, ClipboardError> {
+        let backend = match self {
+            Self::Arboard => ClipboardBackend::Arboard,
+            Self::WlCopy => ClipboardBackend::Command {
+                copy: vec!["wl-copy".to_string()],
+                paste: vec!["wl-paste".to_string(), "--no-newline".to_string()],
+                primary_copy: Some(vec!["wl-copy".to_string(), "--primary".to_string()]),
+                primary_paste: Some(vec![
+                    "wl-paste".to_string(),
+                    "--primary".to_string(),
+                    "--no-newline".to_string(),
+                ]),
+            },
+            Self::Xclip => ClipboardBackend::Command {
+                copy: vec![
+                    "xclip".to_string(),
+                    "-selection".to_string(),
+                    "clipboard".to_string(),
+                ],
+                paste: vec![
+                    "xclip".to_string(),
+                    "-selection".to_string(),
+                    "clipboard".to_string(),
+                    "-o".to_string(),
+                ],
+                primary_copy: Some(vec![
+                    "xclip".to_string(),
+                    "-selection".to_string(),
+                    "primary".to_string(),
+                ]),
+                primary_paste: Some(vec![
+                    "xclip".to_string(),
+                    "-selection".to_string(),
+                    "primary".to_string(),
+                    "-o".to_string(),
+                ]),
+            },
+            Self::Xsel => ClipboardBackend::Command {
+                copy: vec![
+                    "xsel".to_string(),
+                    "--clipboard".to_string(),
+                    "--input".to_string(),
+                ],
+                paste: vec![
+                    "xsel".to_string(),
+                    "--clipboard".to_string(),
+                    "--output".to_string(),
+                ],
+                primary_copy: Some(vec![
+                    "xsel".to_string(),
+                    "--primary".to_string(),
+                    "--input".to_string(),
+                ]),
+                primary_paste: Some(vec![
+                    "xsel".to_string(),
+                    "--primary".to_string(),
+                    "--output".to_string(),
+                ]),
+            },
+            Self::Pbcopy => ClipboardBackend::Command {
+                copy: vec!["pbcopy".to_string()],
+                paste: vec!["pbpaste".to_string()],
+                primary_copy: None,
+                primary_paste: None,
+            },
+            Self::Win32yank => ClipboardBackend::Command {
+                copy: vec!["win32yank.exe".to_string(), "-i".to_string()],
+                paste: vec!["win32yank.exe".to_string(), "-o".to_string()],
+                primary_copy: None,
+                primary_paste: None,
+            },
+            Self::Custom => {
+                let copy = env::var("KLIP_CUSTOM_COPY_COMMAND").map_err(|_| {
+                    ClipboardError::InitializationFailed(
+                        "KLIP_CUSTOM_COPY_COMMAND must be set when using --clipboard-provider custom"
+                            .to_string(),
+                    )
+                })?;
+                let paste = env::var("KLIP_CUSTOM_PASTE_COMMAND").map_err(|_| {
+                    ClipboardError::InitializationFailed(
+                        "KLIP_CUSTOM_PASTE_COMMAND must be set when using --clipboard-provider custom"
+                            .to_string(),
+                    )
+                })?;
+                ClipboardBackend::Command {
+                    copy: copy.split_whitespace().map(str::to_string).collect(),
+                    paste: paste.split_whitespace().map(str::to_string).collect(),
+                    primary_copy: None,
+                    primary_paste: None,
+                }
+            }
+        };
+
+        Ok(backend)
+    }
+}
 
 /// Input parameters for the copy_to_clipboard tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CopyToClipboardInput {
     /// The text content to copy to the clipboard
     pub text: String,
+    /// Which selection to copy into (`clipboard` or `primary`). `primary` is
+    /// the X11 middle-click selection; requesting it against a backend with
+    /// no primary-selection support silently falls back to `clipboard`.
+    /// Defaults to `clipboard`.
+    #[serde(default)]
+    pub selection: Selection,
+}
+
+/// Input parameters for the copy_image_to_clipboard tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CopyImageToClipboardInput {
+    /// Base64-encoded PNG image data to copy to the clipboard
+    pub png_base64: String,
 }
 
 #[derive(Clone)]
 pub struct KlipServer {
     tool_router: ToolRouter<Self>,
+    backend: ClipboardBackend,
 }
 
 #[tool_router]
 impl KlipServer {
-    fn new() -> Self {
+    fn new(backend: ClipboardBackend) -> Self {
         Self {
             tool_router: Self::tool_router(),
+            backend,
         }
     }
 
@@ -51,17 +208,119 @@ impl KlipServer {
         let char_count = params.text.chars().count();
 
         // Create clipboard manager and copy text
-        let mut manager = ClipboardManager::new()
+        let mut manager = ClipboardManager::new(self.backend.clone())
             .map_err(|e| McpError::new(ErrorCode(-32000), e.to_string(), None))?;
 
         manager
-            .copy(&params.text)
+            .copy(&params.text, params.selection)
             .map_err(|e| McpError::new(ErrorCode(-32000), e.to_string(), None))?;
 
         let message = format!("Successfully copied {} characters to clipboard", char_count);
 
         Ok(CallToolResult::success(vec![Content::text(message)]))
     }
+
+    /// Paste text currently on the system clipboard
+    #[rmcp::tool(description = "Read the current text content of the system clipboard")]
+    async fn paste_from_clipboard(&self) -> Result<CallToolResult, McpError> {
+        let mut manager = ClipboardManager::new(self.backend.clone())
+            .map_err(|e| McpError::new(ErrorCode(-32000), e.to_string(), None))?;
+
+        let text = manager
+            .get(Selection::Clipboard)
+            .map_err(|e| McpError::new(ErrorCode(-32000), e.to_string(), None))?;
+
+        if text.is_empty() {
+            let err = ClipboardError::Empty;
+            return Err(McpError::new(ErrorCode(-32000), err.to_string(), None));
+        }
+
+        Ok(CallToolResult::success(vec![Content::text(text)]))
+    }
+
+    /// Copy a base64-encoded PNG image to the system clipboard
+    #[rmcp::tool(description = "Copy a base64-encoded PNG image to the system clipboard")]
+    async fn copy_image_to_clipboard(
+        &self,
+        Parameters(params): Parameters<CopyImageToClipboardInput>,
+    ) -> Result<CallToolResult, McpError> {
+        let image_data = decode_png_base64(&params.png_base64)?;
+
+        let mut manager = ClipboardManager::new(self.backend.clone())
+            .map_err(|e| McpError::new(ErrorCode(-32000), e.to_string(), None))?;
+
+        manager
+            .copy_image(image_data)
+            .map_err(|e| McpError::new(ErrorCode(-32000), e.to_string(), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(
+            "Successfully copied image to clipboard".to_string(),
+        )]))
+    }
+
+    /// Read the image currently on the system clipboard as base64-encoded PNG
+    #[rmcp::tool(
+        description = "Read the current image content of the system clipboard as a base64-encoded PNG"
+    )]
+    async fn paste_image_from_clipboard(&self) -> Result<CallToolResult, McpError> {
+        let mut manager = ClipboardManager::new(self.backend.clone())
+            .map_err(|e| McpError::new(ErrorCode(-32000), e.to_string(), None))?;
+
+        let image = manager
+            .get_image()
+            .map_err(|e| McpError::new(ErrorCode(-32000), e.to_string(), None))?;
+
+        let png_base64 = encode_image_data_as_png_base64(image)?;
+
+        Ok(CallToolResult::success(vec![Content::text(png_base64)]))
+    }
+}
+
+/// Decodes a base64-encoded PNG into RGBA pixel data
+fn decode_png_base64(png_base64: &str) -> Result<ImageData<'static>, McpError> {
+    let png_bytes = BASE64
+        .decode(png_base64)
+        .map_err(|e| McpError::new(ErrorCode(-32000), format!("Invalid base64 data: {e}"), None))?;
+
+    let rgba = image::load_from_memory_with_format(&png_bytes, ImageFormat::Png)
+        .map_err(|e| McpError::new(ErrorCode(-32000), format!("Invalid PNG data: {e}"), None))?
+        .to_rgba8();
+    let (width, height) = rgba.dimensions();
+
+    Ok(ImageData {
+        width: width as usize,
+        height: height as usize,
+        bytes: Cow::Owned(rgba.into_raw()),
+    })
+}
+
+/// Encodes RGBA pixel data as a base64-encoded PNG
+fn encode_image_data_as_png_base64(image: ImageData<'_>) -> Result<String, McpError> {
+    let buffer = image::RgbaImage::from_raw(
+        image.width as u32,
+        image.height as u32,
+        image.bytes.into_owned(),
+    )
+    .ok_or_else(|| {
+        McpError::new(
+            ErrorCode(-32000),
+            "Clipboard image data did not match its reported dimensions".to_string(),
+            None,
+        )
+    })?;
+
+    let mut png_bytes = Vec::new();
+    DynamicImage::ImageRgba8(buffer)
+        .write_to(&mut Cursor::new(&mut png_bytes), ImageFormat::Png)
+        .map_err(|e| {
+            McpError::new(
+                ErrorCode(-32000),
+                format!("Failed to encode clipboard image as PNG: {e}"),
+                None,
+            )
+        })?;
+
+    Ok(BASE64.encode(png_bytes))
 }
 
 #[tool_handler]
@@ -69,7 +328,7 @@ impl ServerHandler for KlipServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             instructions: Some(
-                "A clipboard management server that allows copying text to the system clipboard"
+                "A clipboard management server that allows copying and reading text and images on the system clipboard"
                     .to_string(),
             ),
             capabilities: ServerCapabilities::builder().enable_tools().build(),
@@ -81,7 +340,7 @@ impl ServerHandler for KlipServer {
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Parse CLI arguments (this handles --help and --version automatically)
-    let _cli = Cli::parse();
+    let cli = Cli::parse();
 
     // Initialize tracing/logging
     tracing_subscriber::fmt()
@@ -92,8 +351,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     tracing::info!("Starting klip MCP server");
 
+    // Resolve the clipboard backend: an explicit --clipboard-provider or --osc52
+    // flag wins, otherwise auto-detect the environment (WSL, SSH, Wayland, X11, macOS)
+    let backend = match cli.clipboard_provider {
+        Some(provider) => provider.into_backend()?,
+        None if cli.osc52 => ClipboardBackend::Osc52 {
+            max_len: cli.osc52_max_len,
+        },
+        None => ClipboardBackend::detect(cli.osc52_max_len),
+    };
+
     // Create and run the server
-    let service = KlipServer::new()
+    let service = KlipServer::new(backend)
         .serve(stdio())
         .await
         .inspect_err(|e| eprintln!("Error starting server: {}", e))?;
@@ -104,3 +373,51 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A tiny 2x2 RGBA image, encoded as a base64 PNG, for round-trip tests
+    fn small_png_base64(pixels: &[u8]) -> String {
+        let image = image::RgbaImage::from_raw(2, 2, pixels.to_vec()).unwrap();
+        let mut png_bytes = Vec::new();
+        DynamicImage::ImageRgba8(image)
+            .write_to(&mut Cursor::new(&mut png_bytes), ImageFormat::Png)
+            .unwrap();
+        BASE64.encode(png_bytes)
+    }
+
+    #[test]
+    fn test_png_base64_round_trip() {
+        let pixels: Vec<u8> = vec![
+            255, 0, 0, 255, // red
+            0, 255, 0, 255, // green
+            0, 0, 255, 255, // blue
+            255, 255, 0, 255, // yellow
+        ];
+        let png_base64 = small_png_base64(&pixels);
+
+        let decoded = decode_png_base64(&png_base64).expect("valid PNG should decode");
+        assert_eq!(decoded.width, 2);
+        assert_eq!(decoded.height, 2);
+        assert_eq!(decoded.bytes.as_ref(), pixels.as_slice());
+
+        let reencoded_base64 =
+            encode_image_data_as_png_base64(decoded).expect("RGBA data should encode back to PNG");
+        let redecoded =
+            decode_png_base64(&reencoded_base64).expect("round-tripped PNG should decode");
+        assert_eq!(redecoded.bytes.as_ref(), pixels.as_slice());
+    }
+
+    #[test]
+    fn test_decode_png_base64_rejects_invalid_base64() {
+        assert!(decode_png_base64("not valid base64!!").is_err());
+    }
+
+    #[test]
+    fn test_decode_png_base64_rejects_non_png_data() {
+        let png_base64 = BASE64.encode(b"not a png");
+        assert!(decode_png_base64(&png_base64).is_err());
+    }
+}